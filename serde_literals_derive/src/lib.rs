@@ -0,0 +1,259 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::collections::HashMap;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit};
+
+// serde_literals_derive
+// companion proc-macro for serde_literals: turns a unit-variant enum annotated
+// with #[literal(...)] into a closed literal-union that serialises each
+// variant to its literal and deserialises by matching the incoming value
+// against the arms of its own kind, in declaration order within that kind.
+// A string is compared against every string arm before any char arm, so a
+// string arm wins over a char arm that also matches even when the char arm is
+// declared first (unlike a hand-wired lit_str!/LitChar #[serde(untagged)] enum).
+#[proc_macro_derive(LiteralEnum, attributes(literal))]
+pub fn derive_literal_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let enum_name = &input.ident;
+
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input,
+                "LiteralEnum can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut variants = Vec::new();
+    let mut seen: HashMap<String, syn::Ident> = HashMap::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "LiteralEnum variants must be unit variants",
+            ));
+        }
+
+        let literal_attr = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("literal"))
+            .ok_or_else(|| {
+                syn::Error::new_spanned(variant, "variant is missing a #[literal(...)] attribute")
+            })?;
+        let lit: Lit = literal_attr.parse_args()?;
+
+        let key = lit_dedup_key(&lit);
+        if let Some(prev) = seen.insert(key, variant.ident.clone()) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "literal is ambiguous with variant `{}`: two arms cannot share the same literal",
+                    prev
+                ),
+            ));
+        }
+
+        variants.push((variant.ident.clone(), lit));
+    }
+
+    let serialize_arms = variants.iter().map(|(ident, lit)| {
+        let serialize_call = serialize_call(lit);
+        quote! { #enum_name::#ident => serializer.#serialize_call, }
+    });
+
+    let str_match_arms: Vec<_> = variants
+        .iter()
+        .filter_map(|(ident, lit)| match lit {
+            Lit::Str(s) => Some(quote! { #s => return Ok(#enum_name::#ident), }),
+            _ => None,
+        })
+        .collect();
+
+    let char_if_arms: Vec<_> = variants
+        .iter()
+        .filter_map(|(ident, lit)| match lit {
+            Lit::Char(c) => Some(quote! {
+                if v.starts_with(#c) {
+                    return Ok(#enum_name::#ident);
+                }
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let int_match_arms: Vec<_> = variants
+        .iter()
+        .filter_map(|(ident, lit)| match lit {
+            Lit::Int(i) => Some(quote! { #i => return Ok(#enum_name::#ident), }),
+            _ => None,
+        })
+        .collect();
+
+    let bool_if_arms: Vec<_> = variants
+        .iter()
+        .filter_map(|(ident, lit)| match lit {
+            Lit::Bool(b) => Some(quote! {
+                if v == #b {
+                    return Ok(#enum_name::#ident);
+                }
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let float_if_arms: Vec<_> = variants
+        .iter()
+        .filter_map(|(ident, lit)| match lit {
+            Lit::Float(f) => Some(quote! {
+                if v == #f {
+                    return Ok(#enum_name::#ident);
+                }
+            }),
+            _ => None,
+        })
+        .collect();
+
+    // downstream crates only depend on `serde_literals`, not `serde` directly,
+    // so the expansion must reach serde through `serde_literals`'s own
+    // re-export rather than a bare `serde::...` path that only resolves when
+    // the caller happens to depend on serde itself
+    let serde = quote! { ::serde_literals::serde };
+
+    let visit_str = (!str_match_arms.is_empty() || !char_if_arms.is_empty()).then(|| {
+        quote! {
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: #serde::de::Error,
+            {
+                match v {
+                    #(#str_match_arms)*
+                    _ => {}
+                }
+                #(#char_if_arms)*
+                Err(#serde::de::Error::invalid_value(#serde::de::Unexpected::Str(v), &self))
+            }
+        }
+    });
+
+    let visit_int = (!int_match_arms.is_empty()).then(|| {
+        quote! {
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+            where
+                E: #serde::de::Error,
+            {
+                match v {
+                    #(#int_match_arms)*
+                    _ => {}
+                }
+                Err(#serde::de::Error::invalid_value(#serde::de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: #serde::de::Error,
+            {
+                match v as i64 {
+                    #(#int_match_arms)*
+                    _ => {}
+                }
+                Err(#serde::de::Error::invalid_value(#serde::de::Unexpected::Unsigned(v), &self))
+            }
+        }
+    });
+
+    let visit_bool = (!bool_if_arms.is_empty()).then(|| {
+        quote! {
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+            where
+                E: #serde::de::Error,
+            {
+                #(#bool_if_arms)*
+                Err(#serde::de::Error::invalid_value(#serde::de::Unexpected::Bool(v), &self))
+            }
+        }
+    });
+
+    let visit_float = (!float_if_arms.is_empty()).then(|| {
+        quote! {
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+            where
+                E: #serde::de::Error,
+            {
+                #(#float_if_arms)*
+                Err(#serde::de::Error::invalid_value(#serde::de::Unexpected::Float(v), &self))
+            }
+        }
+    });
+
+    let expecting_msg = format!("a literal of `{}`", enum_name);
+
+    Ok(quote! {
+        impl #serde::Serialize for #enum_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: #serde::Serializer,
+            {
+                match self {
+                    #(#serialize_arms)*
+                }
+            }
+        }
+
+        impl<'de> #serde::Deserialize<'de> for #enum_name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: #serde::Deserializer<'de>,
+            {
+                struct LiteralVisitor;
+
+                impl<'de> #serde::de::Visitor<'de> for LiteralVisitor {
+                    type Value = #enum_name;
+
+                    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        write!(formatter, #expecting_msg)
+                    }
+
+                    #visit_str
+                    #visit_int
+                    #visit_bool
+                    #visit_float
+                }
+
+                deserializer.deserialize_any(LiteralVisitor)
+            }
+        }
+    })
+}
+
+fn lit_dedup_key(lit: &Lit) -> String {
+    match lit {
+        Lit::Str(s) => format!("str:{}", s.value()),
+        Lit::Int(i) => format!("int:{}", i.base10_digits()),
+        Lit::Float(f) => format!("float:{}", f.base10_digits()),
+        Lit::Bool(b) => format!("bool:{}", b.value()),
+        Lit::Char(c) => format!("char:{}", c.value()),
+        _ => "other".to_string(),
+    }
+}
+
+fn serialize_call(lit: &Lit) -> TokenStream2 {
+    match lit {
+        Lit::Str(s) => quote! { serialize_str(#s) },
+        Lit::Int(i) => quote! { serialize_i64(#i) },
+        Lit::Float(f) => quote! { serialize_f64(#f) },
+        Lit::Bool(b) => quote! { serialize_bool(#b) },
+        Lit::Char(c) => quote! { serialize_char(#c) },
+        _ => quote! { compile_error!("unsupported #[literal(...)] value") },
+    }
+}
@@ -1,13 +1,40 @@
+use base64::Engine as _;
 use core::fmt;
 use serde::{
     de::{self, Unexpected, Visitor},
     Deserializer, Serializer,
 };
 
+pub use serde_literals_derive::LiteralEnum;
+
+// re-exported so the `LiteralEnum` expansion can reach serde's traits through
+// `serde_literals::serde` without requiring downstream crates to depend on
+// `serde` directly themselves
+#[doc(hidden)]
+pub use serde;
+
 // serde_literals
 // deserialise and serialise literal strings, ints, floats, bools and chars into enum unit variants
+//
+// caveat: `#[serde(with = "...")]` arms are meant to be combined into a
+// `#[serde(untagged)]` enum, but untagged's own generated code buffers the
+// input through serde's internal `Content` type before dispatching to each
+// arm, and that buffering step always calls `deserialize_any`. That means an
+// untagged literal enum can only round-trip through self-describing formats
+// (JSON and friends) - non-self-describing formats like bincode reject
+// `deserialize_any` outright, and even self-describing formats can't carry
+// `Content` variants `Content` doesn't have (e.g. i128/u128, see `LitI128`/
+// `LitU128`). `LitInt`'s switch to `deserialize_i64` and `LitU64`/`LitI128`/
+// `LitU128` only buy non-self-describing-format support for literals used
+// standalone (as tests/bincode.rs does), not inside a `#[serde(untagged)]` enum.
 pub struct LitStr<'a>(&'a str);
 
+impl<'a> LitStr<'a> {
+    pub fn new(value: &'a str) -> Self {
+        Self(value)
+    }
+}
+
 impl<'a, 'de> Visitor<'de> for LitStr<'a> {
     type Value = ();
 
@@ -27,32 +54,82 @@ impl<'a, 'de> Visitor<'de> for LitStr<'a> {
     }
 }
 
-pub struct LitFloat(f64);
+pub struct LitFloat {
+    value: f64,
+    epsilon: f64,
+}
+
+impl LitFloat {
+    pub fn new(value: f64, epsilon: f64) -> Self {
+        Self { value, epsilon }
+    }
+
+    fn reject_nan<E>(&self) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        if self.value.is_nan() {
+            Err(de::Error::custom(format_args!(
+                "the lit {} is NaN, which can never match a deserialized value",
+                self.value
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
 
 impl<'de> Visitor<'de> for LitFloat {
     type Value = ();
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "the lit {}", self.0)
+        write!(formatter, "the lit {}", self.value)
     }
 
     fn visit_f64<E>(self, v: f64) -> Result<(), E>
     where
         E: de::Error,
     {
-        if v == self.0 {
+        self.reject_nan()?;
+        if (v - self.value).abs() <= self.epsilon {
             Ok(())
         } else {
             Err(de::Error::invalid_value(Unexpected::Float(v), &self))
         }
     }
+
+    // a no-float deterministic encoder may emit an integer-valued literal
+    // such as `lit_float!(Ten, 10.0)` as a plain `10`
+    fn visit_i64<E>(self, v: i64) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        self.reject_nan()?;
+        if (v as f64 - self.value).abs() <= self.epsilon {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Signed(v), &self))
+        }
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        self.reject_nan()?;
+        if (v as f64 - self.value).abs() <= self.epsilon {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
 }
 
 pub struct LitInt<const N: i64>;
 
 impl<const N: i64> LitInt<N> {
     pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
-        deserializer.deserialize_any(Self)
+        deserializer.deserialize_i64(Self)
     }
 
     pub fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
@@ -90,6 +167,99 @@ impl<'de, const N: i64> Visitor<'de> for LitInt<N> {
     }
 }
 
+pub struct LitU64<const N: u64>;
+
+impl<const N: u64> LitU64<N> {
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_u64(Self)
+    }
+
+    pub fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(N)
+    }
+}
+
+impl<'de, const N: u64> Visitor<'de> for LitU64<N> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the lit {}", N)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        if v == N {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
+}
+
+pub struct LitI128<const N: i128>;
+
+impl<const N: i128> LitI128<N> {
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_i128(Self)
+    }
+
+    pub fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i128(N)
+    }
+}
+
+impl<'de, const N: i128> Visitor<'de> for LitI128<N> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the lit {}", N)
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        if v == N {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Other("i128"), &self))
+        }
+    }
+}
+
+pub struct LitU128<const N: u128>;
+
+impl<const N: u128> LitU128<N> {
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_u128(Self)
+    }
+
+    pub fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u128(N)
+    }
+}
+
+impl<'de, const N: u128> Visitor<'de> for LitU128<N> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the lit {}", N)
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        if v == N {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Other("u128"), &self))
+        }
+    }
+}
+
 pub struct LitBool<const B: bool>;
 
 impl<const B: bool> LitBool<B> {
@@ -152,6 +322,95 @@ impl<'de, const C: char> Visitor<'de> for LitChar<C> {
     }
 }
 
+pub struct LitBytes<'a>(&'a [u8]);
+
+impl<'a> LitBytes<'a> {
+    pub fn new(value: &'a [u8]) -> Self {
+        Self(value)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for LitBytes<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the lit {:?}", self.0)
+    }
+
+    // serde's default `visit_borrowed_bytes` forwards to `visit_bytes`, the
+    // same default forwarding `LitInt` already relies on for its int widths
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        if v == self.0 {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Bytes(v), &self))
+        }
+    }
+
+    // human-readable formats without a native bytes type (serde_json among
+    // them) represent `serialize_bytes` output as a plain sequence of u8
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut bytes = Vec::new();
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        if bytes == self.0 {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Bytes(&bytes), &self))
+        }
+    }
+}
+
+// matches a base64 literal against an incoming base64 string (JSON and other
+// human-readable formats) by decoding it and comparing the bytes; the raw
+// bytes path (bincode, Preserves) is handled by `LitBytes` in `lit_base64!`
+pub struct LitBase64Str<'a> {
+    base64: &'a str,
+    decoded: &'a [u8],
+}
+
+impl<'a> LitBase64Str<'a> {
+    pub fn new(base64: &'a str, decoded: &'a [u8]) -> Self {
+        Self { base64, decoded }
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for LitBase64Str<'a> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "the base64 lit {}", self.base64)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<(), E>
+    where
+        E: de::Error,
+    {
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(v)
+            .map_err(|_| de::Error::invalid_value(Unexpected::Str(v), &self))?;
+        if decoded == self.decoded {
+            Ok(())
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Str(v), &self))
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn decode_base64_literal(encoded: &str) -> Vec<u8> {
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .expect("invalid base64 literal passed to lit_base64!")
+}
+
 #[macro_export]
 macro_rules! lit_str {
     ($struct_name:ident, $val:expr) => {
@@ -174,13 +433,17 @@ macro_rules! lit_str {
 #[macro_export]
 macro_rules! lit_float {
     ($struct_name:ident, $val:expr) => {
+        $crate::lit_float!($struct_name, $val, 0.0);
+    };
+    ($struct_name:ident, $val:expr, $epsilon:expr) => {
         pub struct $struct_name;
 
         impl $struct_name {
             pub fn deserialize<'de, D: serde::Deserializer<'de>>(
                 deserializer: D,
             ) -> Result<(), D::Error> {
-                deserializer.deserialize_f64($crate::LitFloat($val as f64))
+                deserializer
+                    .deserialize_f64($crate::LitFloat::new($val as f64, $epsilon as f64))
             }
 
             pub fn serialize<S: serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
@@ -190,6 +453,86 @@ macro_rules! lit_float {
     };
 }
 
+#[macro_export]
+macro_rules! lit_bytes {
+    ($struct_name:ident, $val:expr) => {
+        pub struct $struct_name;
+
+        impl $struct_name {
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<(), D::Error> {
+                deserializer.deserialize_bytes($crate::LitBytes::new($val))
+            }
+
+            pub fn serialize<S: serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_bytes($val)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! lit_base64 {
+    ($struct_name:ident, $val:expr) => {
+        pub struct $struct_name;
+
+        impl $struct_name {
+            fn decoded() -> &'static [u8] {
+                static DECODED: std::sync::OnceLock<Vec<u8>> = std::sync::OnceLock::new();
+                DECODED.get_or_init(|| $crate::decode_base64_literal($val))
+            }
+
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<(), D::Error> {
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_str($crate::LitBase64Str::new($val, Self::decoded()))
+                } else {
+                    deserializer.deserialize_bytes($crate::LitBytes::new(Self::decoded()))
+                }
+            }
+
+            pub fn serialize<S: serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str($val)
+                } else {
+                    serializer.serialize_bytes(Self::decoded())
+                }
+            }
+        }
+    };
+}
+
+// a Preserves symbol or RON bare identifier - formats without that concept
+// (JSON among them) fall back to rendering it as a quoted string via their
+// own `serialize_unit_variant` implementation. Matching it back out on
+// deserialize is just `LitStr` hinted through `deserialize_identifier`
+// instead of `deserialize_str`, so it reuses `LitStr`'s visitor rather than
+// duplicating it.
+#[macro_export]
+macro_rules! lit_symbol {
+    ($struct_name:ident, $val:expr) => {
+        pub struct $struct_name;
+
+        impl $struct_name {
+            pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<(), D::Error> {
+                deserializer.deserialize_identifier($crate::LitStr::new($val))
+            }
+
+            pub fn serialize<S: serde::Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_unit_variant(stringify!($struct_name), 0, $val)
+            }
+        }
+    };
+}
+
+// the derive expands to paths under `::serde_literals::serde`, so tests within
+// this crate need to see themselves under that name too
+extern crate self as serde_literals;
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -275,4 +618,205 @@ mod test {
             Items::SingleChar
         );
     }
+
+    #[derive(Debug, LiteralEnum, PartialEq)]
+    enum Mode {
+        #[literal("auto")]
+        Auto,
+        #[literal(123)]
+        Num123,
+        #[literal(true)]
+        On,
+    }
+
+    #[test]
+    fn test_literal_enum_derive() {
+        assert_eq!(serde_json::to_string(&Mode::Auto).unwrap(), "\"auto\"");
+        assert_eq!(serde_json::to_string(&Mode::Num123).unwrap(), "123");
+        assert_eq!(serde_json::to_string(&Mode::On).unwrap(), "true");
+
+        assert_eq!(serde_json::from_str::<Mode>("\"auto\"").unwrap(), Mode::Auto);
+        assert_eq!(serde_json::from_str::<Mode>("123").unwrap(), Mode::Num123);
+        assert_eq!(serde_json::from_str::<Mode>("true").unwrap(), Mode::On);
+        assert!(serde_json::from_str::<Mode>("\"nope\"").is_err());
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    #[serde(untagged)]
+    enum WideInts {
+        #[serde(with = "LitU64::<18446744073709551615>")]
+        MaxU64,
+        #[serde(with = "LitI128::<170141183460469231731687303715884105727>")]
+        MaxI128,
+        #[serde(with = "LitU128::<340282366920938463463374607431768211455>")]
+        MaxU128,
+        Other(i64),
+    }
+
+    #[test]
+    fn test_wide_ints() {
+        assert_eq!(
+            serde_json::to_string(&WideInts::MaxU64).unwrap(),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            serde_json::to_string(&WideInts::MaxI128).unwrap(),
+            "170141183460469231731687303715884105727"
+        );
+        assert_eq!(
+            serde_json::to_string(&WideInts::MaxU128).unwrap(),
+            "340282366920938463463374607431768211455"
+        );
+
+        // serde_json does implement `deserialize_i128`/`deserialize_u128` (see
+        // `test_lit_i128_deserializes_directly_via_json` below) - what it
+        // can't do is deserialize them *through* `#[serde(untagged)]`, whose
+        // generated code buffers the input into serde's internal `Content`
+        // enum first, and that enum has no i128/u128 variant. So these wide
+        // literals are round-tripped standalone against bincode in
+        // tests/bincode.rs instead, bypassing `#[serde(untagged)]` entirely
+        // (see the comment at the top of this file for why that also rules
+        // out combining them with `#[serde(untagged)]` over bincode)
+        assert_eq!(
+            serde_json::from_str::<WideInts>("18446744073709551615").unwrap(),
+            WideInts::MaxU64
+        );
+        assert_eq!(
+            serde_json::from_str::<WideInts>("7").unwrap(),
+            WideInts::Other(7)
+        );
+    }
+
+    #[test]
+    fn test_lit_i128_deserializes_directly_via_json() {
+        // confirms serde_json itself implements `deserialize_i128`/
+        // `deserialize_u128` just fine - it's the `#[serde(untagged)]`
+        // buffering step in `test_wide_ints` above that can't carry them,
+        // not these literal types or the format
+        let mut de = serde_json::Deserializer::from_str(
+            "170141183460469231731687303715884105727",
+        );
+        LitI128::<170141183460469231731687303715884105727>::deserialize(&mut de).unwrap();
+
+        let mut de = serde_json::Deserializer::from_str(
+            "340282366920938463463374607431768211455",
+        );
+        LitU128::<340282366920938463463374607431768211455>::deserialize(&mut de).unwrap();
+    }
+
+    lit_bytes!(LitMagic, b"magic");
+    lit_base64!(LitHello, "SGVsbG8=");
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    #[serde(untagged)]
+    enum Blob {
+        #[serde(with = "LitMagic")]
+        Magic,
+        #[serde(with = "LitHello")]
+        Hello,
+        Other(String),
+    }
+
+    #[test]
+    fn test_lit_bytes() {
+        assert_eq!(
+            serde_json::to_string(&Blob::Magic).unwrap(),
+            "[109,97,103,105,99]"
+        );
+        assert_eq!(
+            serde_json::from_value::<Blob>(serde_json::json!([109, 97, 103, 105, 99])).unwrap(),
+            Blob::Magic
+        );
+    }
+
+    #[test]
+    fn test_lit_base64_human_readable() {
+        assert_eq!(serde_json::to_string(&Blob::Hello).unwrap(), "\"SGVsbG8=\"");
+        assert_eq!(
+            serde_json::from_str::<Blob>("\"SGVsbG8=\"").unwrap(),
+            Blob::Hello
+        );
+        assert_eq!(
+            serde_json::from_str::<Blob>("\"other\"").unwrap(),
+            Blob::Other("other".into())
+        );
+    }
+
+    lit_float!(Ten, 10.0);
+    lit_float!(Lit3_1Loose, 3.1, 0.0001);
+    lit_float!(LitNan, f64::NAN);
+
+    #[test]
+    fn test_lit_float_nan_never_matches() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        #[serde(untagged)]
+        enum MaybeNan {
+            #[serde(with = "LitNan")]
+            Nan,
+            Number(f64),
+        }
+
+        assert_eq!(
+            serde_json::from_str::<MaybeNan>("1.0").unwrap(),
+            MaybeNan::Number(1.0)
+        );
+    }
+
+    #[test]
+    fn test_lit_float_tolerance_matches_rounded_values() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        #[serde(untagged)]
+        enum Approx {
+            #[serde(with = "Lit3_1Loose")]
+            Pi,
+            Number(f64),
+        }
+
+        assert_eq!(
+            serde_json::from_str::<Approx>("3.10000001").unwrap(),
+            Approx::Pi
+        );
+        assert_eq!(
+            serde_json::from_str::<Approx>("3.2").unwrap(),
+            Approx::Number(3.2)
+        );
+    }
+
+    #[test]
+    fn test_lit_float_matches_integer_token() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        #[serde(untagged)]
+        enum Count {
+            #[serde(with = "Ten")]
+            Ten,
+            Number(f64),
+        }
+
+        assert_eq!(serde_json::from_str::<Count>("10").unwrap(), Count::Ten);
+    }
+
+    lit_symbol!(LitSymbolAuto, "auto");
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    #[serde(untagged)]
+    enum Setting {
+        #[serde(with = "LitSymbolAuto")]
+        Auto,
+        Other(String),
+    }
+
+    #[test]
+    fn test_lit_symbol() {
+        // JSON has no bare-identifier concept, so `serialize_unit_variant`
+        // falls back to a quoted string there
+        assert_eq!(serde_json::to_string(&Setting::Auto).unwrap(), "\"auto\"");
+        assert_eq!(
+            serde_json::from_str::<Setting>("\"auto\"").unwrap(),
+            Setting::Auto
+        );
+        assert_eq!(
+            serde_json::from_str::<Setting>("\"manual\"").unwrap(),
+            Setting::Other("manual".into())
+        );
+    }
 }
@@ -0,0 +1,114 @@
+// bincode is not self-describing, so it cannot answer `deserialize_any` -
+// this locks in that `LitInt` works against it via `deserialize_i64`.
+//
+// note these wrapper structs hand-roll `Serialize`/`Deserialize` instead of
+// using `#[serde(with = "...")]` on a `#[serde(untagged)]` enum the way most
+// tests in src/lib.rs do: untagged's own generated code always buffers
+// through `deserialize_any` first, which bincode rejects outright regardless
+// of literal width (see `untagged_enum_does_not_round_trip_through_bincode`
+// below, and the crate-level doc comment in src/lib.rs).
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_literals::{LitI128, LitInt, LitU128, LitU64};
+
+struct KnownCode;
+
+impl Serialize for KnownCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LitInt::<123>::serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KnownCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LitInt::<123>::deserialize(deserializer)?;
+        Ok(KnownCode)
+    }
+}
+
+#[test]
+fn lit_int_round_trips_through_bincode() {
+    let encoded = bincode::serialize(&KnownCode).unwrap();
+    bincode::deserialize::<KnownCode>(&encoded).unwrap();
+
+    let mismatched = bincode::serialize(&45i64).unwrap();
+    assert!(bincode::deserialize::<KnownCode>(&mismatched).is_err());
+}
+
+// these wide literal types are used standalone here, since inside a
+// `#[serde(untagged)]` enum they cannot round-trip through bincode (nor, for
+// i128/u128, through serde_json); bincode implements the 128-bit and unsigned
+// hints directly.
+struct MaxU64;
+
+impl Serialize for MaxU64 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LitU64::<{ u64::MAX }>::serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaxU64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LitU64::<{ u64::MAX }>::deserialize(deserializer)?;
+        Ok(MaxU64)
+    }
+}
+
+struct MaxI128;
+
+impl Serialize for MaxI128 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LitI128::<{ i128::MAX }>::serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaxI128 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LitI128::<{ i128::MAX }>::deserialize(deserializer)?;
+        Ok(MaxI128)
+    }
+}
+
+struct MaxU128;
+
+impl Serialize for MaxU128 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LitU128::<{ u128::MAX }>::serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MaxU128 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        LitU128::<{ u128::MAX }>::deserialize(deserializer)?;
+        Ok(MaxU128)
+    }
+}
+
+#[test]
+fn lit_wide_ints_round_trip_through_bincode() {
+    let encoded = bincode::serialize(&MaxU64).unwrap();
+    bincode::deserialize::<MaxU64>(&encoded).unwrap();
+
+    let encoded = bincode::serialize(&MaxI128).unwrap();
+    bincode::deserialize::<MaxI128>(&encoded).unwrap();
+
+    let encoded = bincode::serialize(&MaxU128).unwrap();
+    bincode::deserialize::<MaxU128>(&encoded).unwrap();
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum UntaggedCode {
+    #[serde(with = "LitInt::<123>")]
+    Known,
+    Other(i64),
+}
+
+#[test]
+fn untagged_enum_does_not_round_trip_through_bincode() {
+    // locks in the limitation documented above and at the top of src/lib.rs:
+    // `#[serde(untagged)]` always buffers through `deserialize_any`, which
+    // bincode has never supported, regardless of which literal types the
+    // untagged arms use underneath
+    let encoded = bincode::serialize(&UntaggedCode::Known).unwrap();
+    assert!(bincode::deserialize::<UntaggedCode>(&encoded).is_err());
+}
@@ -0,0 +1,7 @@
+// locks in that #[derive(LiteralEnum)] rejects two variants declaring the
+// same literal at compile time, instead of silently picking one arm
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/ambiguous_literal.rs");
+}
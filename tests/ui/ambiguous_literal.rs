@@ -0,0 +1,11 @@
+use serde_literals::LiteralEnum;
+
+#[derive(LiteralEnum)]
+enum Mode {
+    #[literal("auto")]
+    Auto,
+    #[literal("auto")]
+    AlsoAuto,
+}
+
+fn main() {}